@@ -11,7 +11,6 @@ pub use paste::paste;
 use schemars::schema::RootSchema;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::Mutex;
 
 /// The declare macro is used to declare the list of pathways in a Contract trait impl.
 /// formats for calling are:
@@ -77,9 +76,18 @@ macro_rules! declare {
 /// then!(compile_if: [compile_if_1, ... compile_if_n] fn name(self, ctx) {/*Result<Box<Iterator<TransactionTemplate>>>*/} );
 /// /// An Unguarded CTV Function
 /// then!(fn name(self, ctx) {/*Result<Box<Iterator<TransactionTemplate>>>*/} );
+/// /// A Function with checked pre/post-conditions
+/// then!(requires: [|s, ctx| /*bool*/] ensures: [|s, ctx, tmpls| /*bool*/] fn name(self, ctx) {/*...*/} );
 /// /// Null Implementation
 /// then!(name);
 /// ```
+/// `requires:` closures run against `(&self, &ctx)` before the body; `ensures:`
+/// closures run against `(&self, &ctx, &tmpls)` after it, where `ctx` is a clone
+/// of the entry `Context` snapshotted before the body (so the body stays free to
+/// move `ctx` into the template builder as usual). A non-empty `ensures:` list is
+/// what forces the `TxTmplIt` to be collected — otherwise the pathway stays lazy
+/// — and the first clause to return `false` aborts with its index in a
+/// `CompilationError`.
 #[macro_export]
 macro_rules! then {
     {
@@ -98,8 +106,12 @@ macro_rules! then {
             fn $name<'a>() -> Option<$crate::contract::actions::ThenFunc<'a, Self>> {None}
         }
     };
+    // Canonical arm with a non-empty `ensures:` list: the postcondition check
+    // forces materialization of the produced templates.
     {
         $(#[$meta:meta])*
+        requires: [$($req:expr),* $(,)?]
+        ensures: [$($ens:expr),+ $(,)?]
         compile_if: $conditional_compile_list:tt
         guarded_by: $guard_list:tt
         fn $name:ident($s:ident, $ctx:ident)
@@ -109,8 +121,25 @@ macro_rules! then {
         $crate::contract::macros::paste!{
 
             $(#[$meta])*
-            fn [<THEN_ $name>](&$s, $ctx:$crate::contract::Context) -> $crate::contract::TxTmplIt
-            $b
+            fn [<THEN_ $name>](&$s, $ctx:$crate::contract::Context) -> $crate::contract::TxTmplIt {
+                let __requires: &[fn(&Self, &$crate::contract::Context) -> bool] = &[$($req),*];
+                $crate::contract::macros::check_contract_clauses(
+                    "requires", std::stringify!($name),
+                    __requires.iter().map(|__f| __f(&$s, &$ctx)))?;
+                // Snapshot the entry `Context` for the postconditions *before* the
+                // body runs, since idiomatic bodies move `ctx` into the template
+                // builder; `ensures:` therefore observes a clone of the Context as
+                // it was on entry, and must not itself consume it.
+                let __ensures_ctx = $ctx.clone();
+                let __templates: Vec<$crate::template::Template> =
+                    { $b }?.collect::<Result<Vec<_>, $crate::contract::CompilationError>>()?;
+                let __ensures: &[fn(&Self, &$crate::contract::Context, &Vec<$crate::template::Template>) -> bool] =
+                    &[$($ens),*];
+                $crate::contract::macros::check_contract_clauses(
+                    "ensures", std::stringify!($name),
+                    __ensures.iter().map(|__f| __f(&$s, &__ensures_ctx, &__templates)))?;
+                Ok(Box::new(__templates.into_iter().map(Ok)))
+            }
             $(#[$meta])*
             fn $name<'a>() -> Option<$crate::contract::actions::ThenFunc<'a, Self>>{
                 Some($crate::contract::actions::ThenFunc{
@@ -121,12 +150,107 @@ macro_rules! then {
             }
         }
     };
+    // Canonical arm with an empty `ensures:` list: the body stays lazy, only
+    // preconditions are checked.
+    {
+        $(#[$meta:meta])*
+        requires: [$($req:expr),* $(,)?]
+        ensures: []
+        compile_if: $conditional_compile_list:tt
+        guarded_by: $guard_list:tt
+        fn $name:ident($s:ident, $ctx:ident)
+        $b:block
+    } => {
+
+        $crate::contract::macros::paste!{
+
+            $(#[$meta])*
+            fn [<THEN_ $name>](&$s, $ctx:$crate::contract::Context) -> $crate::contract::TxTmplIt {
+                let __requires: &[fn(&Self, &$crate::contract::Context) -> bool] = &[$($req),*];
+                $crate::contract::macros::check_contract_clauses(
+                    "requires", std::stringify!($name),
+                    __requires.iter().map(|__f| __f(&$s, &$ctx)))?;
+                $b
+            }
+            $(#[$meta])*
+            fn $name<'a>() -> Option<$crate::contract::actions::ThenFunc<'a, Self>>{
+                Some($crate::contract::actions::ThenFunc{
+                    guard: &$guard_list,
+                    conditional_compile_if: &$conditional_compile_list,
+                    func: Self::[<THEN_ $name>]
+                })
+            }
+        }
+    };
+    // `requires`/`ensures` supplied without a `compile_if:` list.
+    {
+        $(#[$meta:meta])*
+        requires: $requires_list:tt
+        ensures: $ensures_list:tt
+        guarded_by: $guard_list:tt
+        fn $name:ident($s:ident, $ctx:ident) $b:block
+    } => {
+        then!{
+            $(#[$meta])*
+            requires: $requires_list
+            ensures: $ensures_list
+            compile_if: []
+            guarded_by: $guard_list
+            fn $name($s, $ctx) $b }
+    };
+    // `requires`/`ensures` supplied with a `compile_if:` but no `guarded_by:`.
+    {
+        $(#[$meta:meta])*
+        requires: $requires_list:tt
+        ensures: $ensures_list:tt
+        compile_if: $conditional_compile_list:tt
+        fn $name:ident($s:ident, $ctx:ident) $b:block
+    } => {
+        then!{
+            $(#[$meta])*
+            requires: $requires_list
+            ensures: $ensures_list
+            compile_if: $conditional_compile_list
+            guarded_by: []
+            fn $name($s, $ctx) $b }
+    };
+    // `requires`/`ensures` supplied without any guards.
     {
         $(#[$meta:meta])*
+        requires: $requires_list:tt
+        ensures: $ensures_list:tt
         fn $name:ident($s:ident, $ctx:ident) $b:block
     } => {
         then!{
             $(#[$meta])*
+            requires: $requires_list
+            ensures: $ensures_list
+            compile_if: []
+            guarded_by: []
+            fn $name($s, $ctx) $b }
+    };
+    {
+        $(#[$meta:meta])*
+        compile_if: $conditional_compile_list:tt
+        guarded_by: $guard_list:tt
+        fn $name:ident($s:ident, $ctx:ident) $b:block
+    } => {
+        then!{
+            $(#[$meta])*
+            requires: []
+            ensures: []
+            compile_if: $conditional_compile_list
+            guarded_by: $guard_list
+            fn $name($s, $ctx) $b }
+    };
+    {
+        $(#[$meta:meta])*
+        fn $name:ident($s:ident, $ctx:ident) $b:block
+    } => {
+        then!{
+            $(#[$meta])*
+            requires: []
+            ensures: []
             compile_if: []
             guarded_by: []
             fn $name($s, $ctx) $b
@@ -140,6 +264,8 @@ macro_rules! then {
     } => {
         then!{
             $(#[$meta])*
+            requires: []
+            ensures: []
             compile_if: []
             guarded_by: $guard_list
             fn $name($s, $ctx) $b }
@@ -152,6 +278,8 @@ macro_rules! then {
     } => {
         then!{
             $(#[$meta])*
+            requires: []
+            ensures: []
             compile_if: $conditional_compile_list
             guarded_by: []
             fn $name($s, $ctx) $b }
@@ -159,21 +287,139 @@ macro_rules! then {
 
 }
 
-lazy_static::lazy_static! {
-static ref SCHEMA_MAP: Mutex<HashMap<TypeId, Arc<RootSchema>>> =
-Mutex::new(HashMap::new());
+/// The schema registry caches one `RootSchema` per argument type so that
+/// compiling many contracts with the same `finish!(web{} ...)` argument types
+/// does not rebuild (or re-allocate) identical schemas.
+///
+/// Unlike the previous `Mutex<HashMap>`, readers that hit a cached schema take
+/// only a shared read lock, so concurrent lookups no longer serialize against
+/// one another. With `std::sync::RwLock` a read guard still excludes a writer,
+/// so a lookup briefly contends with the rare installation of a not-yet-cached
+/// type; the common hot path (every lookup of an already-cached schema) never
+/// waits on another reader. On top of the cache it offers pre-warming
+/// ([`register_schema`]), bulk export of the whole warmed cache
+/// ([`export_all_schemas`]) — for a single contract's web API surface use
+/// [`super::export_contract_schemas`] instead — and a stable content hash per
+/// schema ([`schema_hash_for`]) so consumers can detect when an argument ABI has
+/// changed between builds.
+pub mod schema_registry {
+    use super::{Arc, HashMap, RootSchema, TypeId};
+    use bitcoin::hashes::{sha256, Hash};
+    use std::sync::RwLock;
+
+    /// A cached schema together with the metadata needed to export and
+    /// fingerprint it.
+    #[derive(Clone)]
+    pub struct CachedSchema {
+        /// The cached schema.
+        pub schema: Arc<RootSchema>,
+        /// `std::any::type_name` of the schema's type, used as the export key.
+        pub name: &'static str,
+        /// sha256 of the schema's JSON serialization, stable across builds.
+        pub hash: sha256::Hash,
+    }
+
+    lazy_static::lazy_static! {
+        static ref REGISTRY: RwLock<HashMap<TypeId, CachedSchema>> = RwLock::new(HashMap::new());
+    }
+
+    /// Returns the cached schema for `T`, building and caching it on first use.
+    pub fn get_schema_for<T: schemars::JsonSchema + 'static + Sized>() -> Arc<RootSchema> {
+        entry_for::<T>().schema
+    }
+
+    /// Eagerly populates the cache for `T`, so a later lookup on a hot path
+    /// never pays the build cost.
+    pub fn register_schema<T: schemars::JsonSchema + 'static + Sized>() {
+        let _ = entry_for::<T>();
+    }
+
+    /// Returns the stable content hash of `T`'s cached schema.
+    pub fn schema_hash_for<T: schemars::JsonSchema + 'static + Sized>() -> sha256::Hash {
+        entry_for::<T>().hash
+    }
+
+    /// Dumps the process-global cache: every schema that has been looked up or
+    /// pre-registered so far, keyed by type name. This mixes the argument types
+    /// of every contract ever compiled in the process, so it is *not* a single
+    /// contract's web API surface — use [`super::export_contract_schemas`] for
+    /// that. Useful for inspecting or persisting the whole warmed cache.
+    pub fn export_all_schemas() -> HashMap<&'static str, Arc<RootSchema>> {
+        REGISTRY
+            .read()
+            .unwrap()
+            .values()
+            .map(|c| (c.name, c.schema.clone()))
+            .collect()
+    }
+
+    /// Shared read path for cached hits, falling back to building the schema
+    /// outside any lock and then installing it under a brief write lock (losing
+    /// a harmless race to a concurrent writer just discards our freshly built
+    /// copy).
+    fn entry_for<T: schemars::JsonSchema + 'static + Sized>() -> CachedSchema {
+        let id = TypeId::of::<T>();
+        if let Some(found) = REGISTRY.read().unwrap().get(&id) {
+            return found.clone();
+        }
+        let schema = Arc::new(schemars::schema_for!(T));
+        let hash = sha256::Hash::hash(&serde_json::to_vec(&schema).unwrap_or_default());
+        let cached = CachedSchema {
+            schema,
+            name: std::any::type_name::<T>(),
+            hash,
+        };
+        REGISTRY.write().unwrap().entry(id).or_insert(cached).clone()
+    }
 }
+
 /// `get_schema_for` returns a cached RootSchema for a given type.  this is
 /// useful because we might expect to generate the same RootSchema many times,
 /// and they can use a decent amount of memory.
+///
+/// Thin wrapper over [`schema_registry::get_schema_for`]; new code should
+/// prefer the registry API directly.
 pub fn get_schema_for<T: schemars::JsonSchema + 'static + Sized>(
 ) -> Arc<schemars::schema::RootSchema> {
-    SCHEMA_MAP
-        .lock()
-        .unwrap()
-        .entry(TypeId::of::<T>())
-        .or_insert_with(|| Arc::new(schemars::schema_for!(T)))
-        .clone()
+    schema_registry::get_schema_for::<T>()
+}
+
+/// Dumps the web API surface of a single contract as one bundle, keyed by
+/// pathway name, by walking its `FINISH_OR_FUNCS` and collecting the schema each
+/// `FinishOrFunc` carries (only the `web{}`-enabled ones have a schema; the rest
+/// are skipped). Unlike [`schema_registry::export_all_schemas`], this is scoped
+/// to one contract and does not depend on the argument types having been looked
+/// up beforehand — instantiating the pathways is enough.
+pub fn export_contract_schemas<C: crate::contract::Contract>(
+) -> HashMap<String, Arc<schemars::schema::RootSchema>> {
+    let mut out = HashMap::new();
+    for f in C::FINISH_OR_FUNCS.iter().filter_map(|f| f()) {
+        if let Some(schema) = f.get_schema().clone() {
+            out.insert(f.get_name().to_string(), schema);
+        }
+    }
+    out
+}
+
+/// Shared helper for the `requires:`/`ensures:` clauses of `then!`/`finish!`.
+///
+/// Walks the already-evaluated clause results in order and, on the first
+/// `false`, short-circuits with a `CompilationError` naming the clause `kind`
+/// (`"requires"`/`"ensures"`), its index, and the `pathway` it guards. Factored
+/// out so the four generated arms share one copy of the error-reporting logic.
+pub fn check_contract_clauses(
+    kind: &str,
+    pathway: &str,
+    results: impl Iterator<Item = bool>,
+) -> Result<(), crate::contract::CompilationError> {
+    for (i, ok) in results.enumerate() {
+        if !ok {
+            return Err(crate::contract::CompilationError::Custom(
+                format!("{}[{}] failed for pathway `{}`", kind, i, pathway).into(),
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// Internal Helper for finish! macro, not to be used directly.
@@ -181,12 +427,16 @@ pub fn get_schema_for<T: schemars::JsonSchema + 'static + Sized>(
 macro_rules! web_api {
     {web{},$name:ident,$type:ty} => {
         $crate::contract::macros::paste!{
-            const [<FINISH_API_FOR_ $name >] : Option<std::sync::Arc<$crate::schemars::schema::RootSchema>> = Some($crate::contract::macros::get_schema_for::<$type>());
+            fn [<FINISH_API_FOR_ $name >]() -> Option<std::sync::Arc<$crate::schemars::schema::RootSchema>> {
+                Some($crate::contract::macros::schema_registry::get_schema_for::<$type>())
+            }
         }
     };
     {$name:ident,$type:ty} => {
         $crate::contract::macros::paste!{
-            const [<FINISH_API_FOR_ $name >] : Option<std::sync::Arc<$crate::schemars::schema::RootSchema>> = None;
+            fn [<FINISH_API_FOR_ $name >]() -> Option<std::sync::Arc<$crate::schemars::schema::RootSchema>> {
+                None
+            }
         }
     }
 }
@@ -194,13 +444,23 @@ macro_rules! web_api {
 /// formats for calling are:
 /// ```ignore
 /// /// A Guarded CTV Function
-/// finish!(guarded_by: [guard_1, ... guard_n] fn name(self, ctx, o) {/*Result<Box<Iterator<TransactionTemplate>>>*/} );
+/// finish!(guarded_by: [guard_1, ... guard_n] coerce_args: c fn name(self, ctx, o: T) {/*Result<Box<Iterator<TransactionTemplate>>>*/} );
 /// /// A Conditional CTV Function
-/// finish!(compile_if: [compile_if_1, ... compile_if_n] guarded_by: [guard_1, ..., guard_n] fn name(self, ctx, o) {/*Result<Box<Iterator<TransactionTemplate>>>*/} );
+/// finish!(compile_if: [compile_if_1, ... compile_if_n] guarded_by: [guard_1, ..., guard_n] coerce_args: c fn name(self, ctx, o: T) {/*Result<Box<Iterator<TransactionTemplate>>>*/} );
+/// /// A Function with checked pre/post-conditions
+/// finish!(requires: [|s, ctx, o| /*bool*/] ensures: [|s, ctx, tmpls| /*bool*/] guarded_by: [...] coerce_args: c fn name(self, ctx, o: T) {/*...*/} );
 /// /// Null Implementation
 /// finish!(name);
 /// ```
 /// Unlike a `then!`, `finish!` must always have guards.
+///
+/// Preconditions in `requires:` see the coerced argument too — they run against
+/// `(&self, &ctx, &o)` before the body; `ensures:` postconditions run against
+/// `(&self, &ctx, &tmpls)` once the templates have been collected, where `ctx`
+/// is a clone of the entry `Context` snapshotted before the body (so the body
+/// stays free to move `ctx` into the template builder). Supplying any `ensures:`
+/// clause is what defeats laziness here; verification stops at the first failing
+/// clause, whose index is reported in the `CompilationError`.
 #[macro_export]
 macro_rules! finish {
     {
@@ -224,9 +484,13 @@ macro_rules! finish {
             }
         }
     };
+    // Canonical arm with a non-empty `ensures:` list: the postcondition check
+    // forces materialization of the produced templates.
     {
         $(#[$meta:meta])*
         $(web$web_enable:block)?
+        requires: [$($req:expr),* $(,)?]
+        ensures: [$($ens:expr),+ $(,)?]
         compile_if: $conditional_compile_list:tt
         guarded_by: $guard_list:tt
         coerce_args: $coerce_args:ident
@@ -237,8 +501,25 @@ macro_rules! finish {
         $crate::contract::macros::paste!{
             web_api!($(web$web_enable,)* $name,$arg_type);
             $(#[$meta])*
-            fn [<FINISH_ $name>](&$s, $ctx:$crate::contract::Context, $o: $arg_type) -> $crate::contract::TxTmplIt
-            $b
+            fn [<FINISH_ $name>](&$s, $ctx:$crate::contract::Context, $o: $arg_type) -> $crate::contract::TxTmplIt {
+                let __requires: &[fn(&Self, &$crate::contract::Context, &$arg_type) -> bool] = &[$($req),*];
+                $crate::contract::macros::check_contract_clauses(
+                    "requires", std::stringify!($name),
+                    __requires.iter().map(|__f| __f(&$s, &$ctx, &$o)))?;
+                // Snapshot the entry `Context` for the postconditions *before* the
+                // body runs, since idiomatic bodies move `ctx` into the template
+                // builder; `ensures:` therefore observes a clone of the Context as
+                // it was on entry, and must not itself consume it.
+                let __ensures_ctx = $ctx.clone();
+                let __templates: Vec<$crate::template::Template> =
+                    { $b }?.collect::<Result<Vec<_>, $crate::contract::CompilationError>>()?;
+                let __ensures: &[fn(&Self, &$crate::contract::Context, &Vec<$crate::template::Template>) -> bool] =
+                    &[$($ens),*];
+                $crate::contract::macros::check_contract_clauses(
+                    "ensures", std::stringify!($name),
+                    __ensures.iter().map(|__f| __f(&$s, &__ensures_ctx, &__templates)))?;
+                Ok(Box::new(__templates.into_iter().map(Ok)))
+            }
             $(#[$meta])*
             fn $name<'a>() -> Option<Box<dyn
             $crate::contract::actions::CallableAsFoF<Self, <Self as $crate::contract::Contract>::StatefulArguments>>>
@@ -248,13 +529,92 @@ macro_rules! finish {
                     guard: &$guard_list,
                     conditional_compile_if: &$conditional_compile_list,
                     func: Self::[<FINISH_ $name>],
-                    schema: Self::[<FINISH_API_FOR_ $name >],
+                    schema: Self::[<FINISH_API_FOR_ $name >](),
                     name: std::stringify!($name).into()
                 };
                 Some(Box::new(f))
             }
         }
     };
+    // Canonical arm with an empty `ensures:` list: the body stays lazy, only
+    // preconditions are checked.
+    {
+        $(#[$meta:meta])*
+        $(web$web_enable:block)?
+        requires: [$($req:expr),* $(,)?]
+        ensures: []
+        compile_if: $conditional_compile_list:tt
+        guarded_by: $guard_list:tt
+        coerce_args: $coerce_args:ident
+        fn $name:ident($s:ident, $ctx:ident, $o:ident : $arg_type:ty)
+        $b:block
+    } => {
+
+        $crate::contract::macros::paste!{
+            web_api!($(web$web_enable,)* $name,$arg_type);
+            $(#[$meta])*
+            fn [<FINISH_ $name>](&$s, $ctx:$crate::contract::Context, $o: $arg_type) -> $crate::contract::TxTmplIt {
+                let __requires: &[fn(&Self, &$crate::contract::Context, &$arg_type) -> bool] = &[$($req),*];
+                $crate::contract::macros::check_contract_clauses(
+                    "requires", std::stringify!($name),
+                    __requires.iter().map(|__f| __f(&$s, &$ctx, &$o)))?;
+                $b
+            }
+            $(#[$meta])*
+            fn $name<'a>() -> Option<Box<dyn
+            $crate::contract::actions::CallableAsFoF<Self, <Self as $crate::contract::Contract>::StatefulArguments>>>
+            {
+                let f = $crate::contract::actions::FinishOrFunc{
+                    coerce_args: $coerce_args,
+                    guard: &$guard_list,
+                    conditional_compile_if: &$conditional_compile_list,
+                    func: Self::[<FINISH_ $name>],
+                    schema: Self::[<FINISH_API_FOR_ $name >](),
+                    name: std::stringify!($name).into()
+                };
+                Some(Box::new(f))
+            }
+        }
+    };
+    // `requires`/`ensures` supplied without a `compile_if:` list.
+    {
+        $(#[$meta:meta])*
+        $(web$web_enable:block)?
+        requires: $requires_list:tt
+        ensures: $ensures_list:tt
+        guarded_by: $guard_list:tt
+        coerce_args: $coerce_args:ident
+        fn $name:ident($s:ident, $ctx:ident, $o:ident:$arg_type:ty) $b:block
+    } => {
+        finish!{
+            $(#[$meta])*
+            $(web$web_enable)*
+            requires: $requires_list
+            ensures: $ensures_list
+            compile_if: []
+            guarded_by: $guard_list
+            coerce_args: $coerce_args
+            fn $name($s, $ctx, $o:$arg_type) $b }
+    };
+    {
+        $(#[$meta:meta])*
+        $(web$web_enable:block)?
+        compile_if: $conditional_compile_list:tt
+        guarded_by: $guard_list:tt
+        coerce_args: $coerce_args:ident
+        fn $name:ident($s:ident, $ctx:ident, $o:ident : $arg_type:ty)
+        $b:block
+    } => {
+        finish!{
+            $(#[$meta])*
+            $(web$web_enable)*
+            requires: []
+            ensures: []
+            compile_if: $conditional_compile_list
+            guarded_by: $guard_list
+            coerce_args: $coerce_args
+            fn $name($s, $ctx, $o:$arg_type) $b }
+    };
     {
         $(#[$meta:meta])*
         $(web$web_enable:block)?
@@ -265,6 +625,8 @@ macro_rules! finish {
         finish!{
             $(#[$meta])*
             $(web$web_enable)*
+            requires: []
+            ensures: []
             compile_if: []
             guarded_by: $guard_list
             coerce_args: $coerce_args
@@ -363,3 +725,79 @@ macro_rules! compile_if {
             }
         };
 }
+
+/// The verify_contract macro emits a `#[test]` that compiles a contract and
+/// fails if compilation panics or returns a `CompilationError`, sweeping an
+/// unwind bound so recursively-defined (`updatable`) contracts are checked at a
+/// range of expansion depths without running forever.
+///
+/// formats for calling are:
+/// ```ignore
+/// verify_contract!(SomeContract, strategy);
+/// verify_contract!(SomeContract, strategy, unwind = N);
+/// ```
+///
+/// `strategy` is a `Fn(usize) -> (SomeContract, Context)`: it receives an unwind
+/// bound and returns a fresh instance plus the `Context` to compile it in, with
+/// the bound used to cap how deeply a self-referential contract should expand.
+/// The test compiles the instance at every depth `1..=N` (depth 1 alone when
+/// `N` is 0), then fails if any depth panicked or errored, printing a report
+/// with the outcome of each depth.
+///
+/// Note: this is a compile smoke-check, not a model-checker — it does not
+/// enumerate or force the contract's `compile_if` outcomes, which are decided
+/// inside `compile` by the contract's own `COMPILE_IF_*` methods against the
+/// `Context`. To exercise a specific compile-if state, have `strategy` return a
+/// `Context` that drives those methods that way.
+#[macro_export]
+macro_rules! verify_contract {
+    ($contract:ident, $strategy:expr $(,)?) => {
+        $crate::verify_contract!($contract, $strategy, unwind = 8);
+    };
+    ($contract:ident, $strategy:expr, unwind = $n:expr $(,)?) => {
+        $crate::contract::macros::paste! {
+            #[test]
+            fn [<verify_contract_ $contract:snake>]() {
+                use $crate::contract::Compilable;
+                const UNWIND: usize = $n;
+
+                let mut report = String::new();
+                let mut failures: usize = 0;
+                // Compile at each expansion depth up to the unwind bound so a
+                // recursive contract is checked shallow-to-deep and still ends;
+                // `unwind = 0` still checks one state rather than passing vacuously.
+                for depth in 1..=UNWIND.max(1) {
+                    let (contract, ctx) = ($strategy)(depth);
+                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        contract.compile(ctx)
+                    }));
+                    match result {
+                        Ok(Ok(_)) => report.push_str(&format!("[ok]    depth {}\n", depth)),
+                        Ok(Err(e)) => {
+                            failures += 1;
+                            report.push_str(&format!("[error] depth {} -> {:?}\n", depth, e));
+                        }
+                        Err(_) => {
+                            failures += 1;
+                            report.push_str(&format!("[panic] depth {}\n", depth));
+                        }
+                    }
+                }
+
+                println!(
+                    "verify_contract!({}) checked {} depth(s):\n{}",
+                    std::stringify!($contract),
+                    UNWIND.max(1),
+                    report
+                );
+                assert!(
+                    failures == 0,
+                    "contract {} failed to compile at {} depth(s):\n{}",
+                    std::stringify!($contract),
+                    failures,
+                    report
+                );
+            }
+        }
+    };
+}